@@ -1,6 +1,9 @@
-use hir::{Substs, Ty};
+use std::collections::HashSet;
 
-use crate::completion::{CompletionContext, Completions};
+use hir::{Substs, StructField, Ty};
+use ra_syntax::ast::AstNode;
+
+use crate::completion::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
 
 /// Complete fields in fields literals.
 pub(super) fn complete_struct_literal(acc: &mut Completions, ctx: &CompletionContext) {
@@ -16,9 +19,60 @@ pub(super) fn complete_struct_literal(acc: &mut Completions, ctx: &CompletionCon
         _ => Substs::empty(),
     };
 
-    for field in variant.fields(ctx.db) {
+    // Exclude the field the cursor is currently in: if the user has typed a field's full name,
+    // e.g. `A { a<|> }`, that name would otherwise land in the set and hide `a` from completion.
+    let already_present_names = ctx
+        .struct_lit_syntax
+        .and_then(|it| it.named_field_list())
+        .map(|field_list| {
+            field_list
+                .fields()
+                .filter(|field| !field.syntax().range().contains_inclusive(ctx.offset))
+                .filter_map(|field| field.name_ref())
+                .map(|name_ref| name_ref.text().to_string())
+                .collect::<HashSet<_>>()
+        })
+        .unwrap_or_default();
+
+    let remaining_fields: Vec<_> = variant
+        .fields(ctx.db)
+        .into_iter()
+        .filter(|field| !already_present_names.contains(field.name(ctx.db).to_string().as_str()))
+        .collect();
+
+    for &field in &remaining_fields {
         acc.add_field(ctx, field, &ty_substs);
     }
+
+    complete_fill_all_fields(acc, ctx, &remaining_fields);
+}
+
+/// Adds a single completion that fills in every field that hasn't been
+/// written yet, e.g. `a: (), b: ()`.
+fn complete_fill_all_fields(acc: &mut Completions, ctx: &CompletionContext, remaining_fields: &[StructField]) {
+    if remaining_fields.is_empty() {
+        return;
+    }
+
+    let insert = remaining_fields
+        .iter()
+        .map(|field| format!("{}: ()", field.name(ctx.db)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let detail = remaining_fields
+        .iter()
+        .map(|field| field.ty(ctx.db).display(ctx.db).to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    acc.add(
+        CompletionItem::new(CompletionKind::Reference, ctx.source_range(), "…")
+            .kind(CompletionItemKind::Snippet)
+            .detail(detail)
+            .insert_text(insert)
+            .build(),
+    );
 }
 
 #[cfg(test)]
@@ -106,6 +160,165 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_struct_literal_partial_fields() {
+        let completions = complete(
+            r"
+            struct A { a: u32, b: u32, c: u32 }
+            fn foo() {
+               A { a: 1, <|> }
+            }
+            ",
+        );
+        assert_debug_snapshot_matches!(completions, @r###"
+       ⋮[
+       ⋮    CompletionItem {
+       ⋮        label: "b",
+       ⋮        source_range: [97; 97),
+       ⋮        delete: [97; 97),
+       ⋮        insert: "b",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "c",
+       ⋮        source_range: [97; 97),
+       ⋮        delete: [97; 97),
+       ⋮        insert: "c",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "…",
+       ⋮        source_range: [97; 97),
+       ⋮        delete: [97; 97),
+       ⋮        insert: "b: (), c: ()",
+       ⋮        kind: Snippet,
+       ⋮        detail: "u32, u32",
+       ⋮    },
+       ⋮]
+        "###);
+    }
+
+    #[test]
+    fn test_struct_literal_field_name_typed_in_full() {
+        let completions = complete(
+            r"
+            struct A { a: u32 }
+            fn foo() {
+               A { a<|> }
+            }
+            ",
+        );
+        assert_debug_snapshot_matches!(completions, @r###"
+       ⋮[
+       ⋮    CompletionItem {
+       ⋮        label: "a",
+       ⋮        source_range: [75; 76),
+       ⋮        delete: [75; 76),
+       ⋮        insert: "a",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "…",
+       ⋮        source_range: [75; 76),
+       ⋮        delete: [75; 76),
+       ⋮        insert: "a: ()",
+       ⋮        kind: Snippet,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮]
+        "###);
+    }
+
+    #[test]
+    fn test_struct_literal_enum_variant_partial_fields() {
+        let completions = complete(
+            r"
+            enum E {
+                A { a: u32, b: u32 }
+            }
+            fn foo() {
+                let _ = E::A { a: 1, <|> }
+            }
+            ",
+        );
+        assert_debug_snapshot_matches!(completions, @r###"
+       ⋮[
+       ⋮    CompletionItem {
+       ⋮        label: "b",
+       ⋮        source_range: [133; 133),
+       ⋮        delete: [133; 133),
+       ⋮        insert: "b",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "…",
+       ⋮        source_range: [133; 133),
+       ⋮        delete: [133; 133),
+       ⋮        insert: "b: ()",
+       ⋮        kind: Snippet,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮]
+        "###);
+    }
+
+    #[test]
+    fn test_struct_literal_fill_all_fields() {
+        let completions = complete(
+            r"
+            struct A { a: u32, b: u32 }
+            fn foo() {
+               A { <|> }
+            }
+            ",
+        );
+        assert_debug_snapshot_matches!(completions, @r###"
+       ⋮[
+       ⋮    CompletionItem {
+       ⋮        label: "a",
+       ⋮        source_range: [83; 83),
+       ⋮        delete: [83; 83),
+       ⋮        insert: "a",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "b",
+       ⋮        source_range: [83; 83),
+       ⋮        delete: [83; 83),
+       ⋮        insert: "b",
+       ⋮        kind: Field,
+       ⋮        detail: "u32",
+       ⋮    },
+       ⋮    CompletionItem {
+       ⋮        label: "…",
+       ⋮        source_range: [83; 83),
+       ⋮        delete: [83; 83),
+       ⋮        insert: "a: (), b: ()",
+       ⋮        kind: Snippet,
+       ⋮        detail: "u32, u32",
+       ⋮    },
+       ⋮]
+        "###);
+    }
+
+    #[test]
+    fn test_struct_literal_fill_all_fields_suppressed_when_complete() {
+        let completions = complete(
+            r"
+            struct A { a: u32 }
+            fn foo() {
+               A { a: 1, <|> }
+            }
+            ",
+        );
+        assert_debug_snapshot_matches!(completions, @r###"[]"###);
+    }
+
     #[test]
     fn test_struct_literal_generic_struct() {
         let completions = complete(
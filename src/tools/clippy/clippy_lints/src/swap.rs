@@ -4,6 +4,7 @@ use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{can_mut_borrow_both, eq_expr_value, in_constant, std_or_core};
 use if_chain::if_chain;
+use rustc_ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -16,8 +17,6 @@ declare_clippy_lint! {
     /// ### What it does
     /// Checks for manual swapping.
     ///
-    /// Note that the lint will not be emitted in const blocks, as the suggestion would not be applicable.
-    ///
     /// ### Why is this bad?
     /// The `std::mem::swap` function exposes the intent better
     /// without deinitializing or copying either variable.
@@ -69,12 +68,43 @@ declare_clippy_lint! {
     "`foo = bar; bar = foo` sequence"
 }
 
-declare_lint_pass!(Swap => [MANUAL_SWAP, ALMOST_SWAPPED]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual cyclic rotation of three or more variables.
+    ///
+    /// ### Why is this bad?
+    /// A destructuring assignment, or `slice::rotate_left`/`slice::rotate_right`
+    /// for the slice-index case, expresses the intent far more clearly than a
+    /// temp variable and a chain of assignments.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (mut a, mut b, mut c) = (1, 2, 3);
+    /// let t = a;
+    /// a = b;
+    /// b = c;
+    /// c = t;
+    /// ```
+    /// Use destructuring assignment instead:
+    /// ```rust
+    /// # let (mut a, mut b, mut c) = (1, 2, 3);
+    /// (a, b, c) = (b, c, a);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_ROTATION,
+    complexity,
+    "manual cyclic rotation of three or more variables"
+}
+
+declare_lint_pass!(Swap => [MANUAL_SWAP, MANUAL_ROTATION, ALMOST_SWAPPED]);
 
 impl<'tcx> LateLintPass<'tcx> for Swap {
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
         check_manual_swap(cx, block);
+        check_manual_swap_tuple(cx, block);
+        check_manual_rotation(cx, block);
         check_suspicious_swap(cx, block);
+        check_tuple_assign_swap(cx, block);
         check_xor_swap(cx, block);
     }
 }
@@ -85,7 +115,9 @@ fn generate_swap_warning(cx: &LateContext<'_>, e1: &Expr<'_>, e2: &Expr<'_>, spa
     if !can_mut_borrow_both(cx, e1, e2) {
         if let ExprKind::Index(lhs1, idx1) = e1.kind {
             if let ExprKind::Index(lhs2, idx2) = e2.kind {
-                if eq_expr_value(cx, lhs1, lhs2) {
+                // `<[T]>::swap` is not const-stable, so bail out on a const-context swap rather
+                // than suggesting code that won't compile there.
+                if eq_expr_value(cx, lhs1, lhs2) && !in_constant(cx, e1.hir_id) {
                     let ty = cx.typeck_results().expr_ty(lhs1).peel_refs();
 
                     if matches!(ty.kind(), ty::Slice(_))
@@ -117,7 +149,21 @@ fn generate_swap_warning(cx: &LateContext<'_>, e1: &Expr<'_>, e2: &Expr<'_>, spa
 
     let first = Sugg::hir_with_applicability(cx, e1, "..", &mut applicability);
     let second = Sugg::hir_with_applicability(cx, e2, "..", &mut applicability);
-    let Some(sugg) = std_or_core(cx) else { return };
+
+    // `std::mem::swap` isn't usable in a const context, but destructuring assignment is.
+    if in_constant(cx, e1.hir_id) || std_or_core(cx).is_none() {
+        span_lint_and_sugg(
+            cx,
+            MANUAL_SWAP,
+            span,
+            &format!("this looks like you are swapping `{first}` and `{second}` manually"),
+            "try",
+            format!("({first}, {second}) = ({second}, {first})"),
+            applicability,
+        );
+        return;
+    }
+    let sugg = std_or_core(cx).unwrap();
 
     span_lint_and_then(
         cx,
@@ -140,10 +186,6 @@ fn generate_swap_warning(cx: &LateContext<'_>, e1: &Expr<'_>, e2: &Expr<'_>, spa
 
 /// Implementation of the `MANUAL_SWAP` lint.
 fn check_manual_swap(cx: &LateContext<'_>, block: &Block<'_>) {
-    if in_constant(cx, block.hir_id) {
-        return;
-    }
-
     for w in block.stmts.windows(3) {
         if_chain! {
             // let t = foo();
@@ -172,6 +214,176 @@ fn check_manual_swap(cx: &LateContext<'_>, block: &Block<'_>) {
     }
 }
 
+/// Implementation of the `MANUAL_SWAP` lint for a manual swap that is
+/// finished off with a tuple-destructuring assignment instead of two plain
+/// assignments, e.g. `let t = a; (a, b) = (b, t);`.
+fn check_manual_swap_tuple(cx: &LateContext<'_>, block: &Block<'_>) {
+    for [local, assign] in block.stmts.array_windows() {
+        if_chain! {
+            // let t = foo();
+            if let StmtKind::Local(tmp) = local.kind;
+            if let Some(tmp_init) = tmp.init;
+            if let PatKind::Binding(.., ident, None) = tmp.pat.kind;
+
+            // (foo(), bar()) = (bar(), t);
+            if let StmtKind::Semi(expr) = assign.kind;
+            if let ExprKind::Assign(lhs, rhs, _) = expr.kind;
+            if let ExprKind::Tup([lhs1, lhs2]) = lhs.kind;
+            if let ExprKind::Tup([rhs1, rhs2]) = rhs.kind;
+            if let ExprKind::Path(QPath::Resolved(None, rhs2_path)) = rhs2.kind;
+            if rhs2_path.segments.len() == 1;
+
+            if ident.name == rhs2_path.segments[0].ident.name;
+            if eq_expr_value(cx, tmp_init, lhs1);
+            if eq_expr_value(cx, rhs1, lhs2);
+            then {
+                let span = local.span.to(assign.span);
+                generate_swap_warning(cx, lhs1, lhs2, span, false);
+            }
+        }
+    }
+}
+
+/// Implementation of the `MANUAL_ROTATION` lint.
+///
+/// Looks for a run of consecutive statements of the form
+/// `let t = p0; p0 = p1; p1 = p2; ...; p(n-1) = t;` which rotates the values
+/// held by `p0..p(n-1)` by one position. The places are tracked as a
+/// permutation graph (each place mapped to the place it receives its new
+/// value from); a match requires the graph to form a single cycle of at
+/// least three places, each touched exactly once.
+fn check_manual_rotation(cx: &LateContext<'_>, block: &Block<'_>) {
+    let stmts = block.stmts;
+
+    'outer: for start in 0..stmts.len() {
+        let StmtKind::Local(tmp) = stmts[start].kind else { continue };
+        let Some(tmp_init) = tmp.init else { continue };
+        let PatKind::Binding(.., ident, None) = tmp.pat.kind else { continue };
+
+        let mut places = vec![tmp_init];
+        let mut end = start + 1;
+        loop {
+            let Some(stmt) = stmts.get(end) else {
+                continue 'outer;
+            };
+            let StmtKind::Semi(expr) = stmt.kind else {
+                continue 'outer;
+            };
+            let ExprKind::Assign(lhs, rhs, _) = expr.kind else {
+                continue 'outer;
+            };
+            if !eq_expr_value(cx, places.last().unwrap(), lhs) {
+                continue 'outer;
+            }
+
+            if let ExprKind::Path(QPath::Resolved(None, path)) = rhs.kind
+                && let [segment] = &path.segments
+                && segment.ident.name == ident.name
+            {
+                if places.len() >= 3 {
+                    let span = stmts[start].span.to(stmt.span);
+                    generate_rotation_warning(cx, &places, span);
+                }
+                continue 'outer;
+            }
+
+            places.push(rhs);
+            end += 1;
+        }
+    }
+}
+
+/// Checks that every place in `places` can be mutably borrowed independently
+/// of every other, i.e. none of them alias each other.
+fn places_are_disjoint(cx: &LateContext<'_>, places: &[&Expr<'_>]) -> bool {
+    places
+        .iter()
+        .enumerate()
+        .all(|(i, a)| places[i + 1..].iter().all(|b| can_mut_borrow_both(cx, a, b)))
+}
+
+/// Returns the index of `place` if it is a constant-index expression into `container`.
+fn index_into(cx: &LateContext<'_>, place: &Expr<'_>, container: &Expr<'_>) -> Option<u128> {
+    if let ExprKind::Index(lhs, idx) = place.kind
+        && eq_expr_value(cx, lhs, container)
+        && let ExprKind::Lit(lit) = idx.kind
+        && let LitKind::Int(value, _) = lit.node
+    {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn generate_rotation_warning(cx: &LateContext<'_>, places: &[&Expr<'_>], span: Span) {
+    let mut applicability = Applicability::MachineApplicable;
+
+    // Mirrors `generate_swap_warning`: the same-container index case is checked first, since
+    // `places_are_disjoint` (via `can_mut_borrow_both`) can't prove two indices into the same
+    // container are disjoint and would otherwise reject this case outright.
+    if let ExprKind::Index(container, _) = places[0].kind {
+        let indices: Option<Vec<u128>> = places.iter().map(|place| index_into(cx, place, container)).collect();
+        if let Some(indices) = indices {
+            let ascending = indices.windows(2).all(|w| w[1] == w[0] + 1);
+            let descending = indices.windows(2).all(|w| w[0] == w[1] + 1);
+
+            // `<[T]>::rotate_left`/`rotate_right` are not const-stable, so this suggestion
+            // would fail to compile in a const context; skip it just like the MANUAL_SWAP case.
+            if (ascending || descending) && !in_constant(cx, container.hir_id) {
+                let ty = cx.typeck_results().expr_ty(container).peel_refs();
+                if matches!(ty.kind(), ty::Slice(_))
+                    || matches!(ty.kind(), ty::Array(_, _))
+                    || is_type_diagnostic_item(cx, ty, sym::Vec)
+                    || is_type_diagnostic_item(cx, ty, sym::VecDeque)
+                {
+                    // Only the `lo..=hi` sub-range is touched by the manual code; rotating the
+                    // whole container would also shuffle any untouched elements.
+                    let lo = indices.iter().min().unwrap();
+                    let hi = indices.iter().max().unwrap();
+                    let slice = Sugg::hir_with_applicability(cx, container, "<slice>", &mut applicability);
+                    let method = if ascending { "rotate_left" } else { "rotate_right" };
+                    span_lint_and_sugg(
+                        cx,
+                        MANUAL_ROTATION,
+                        span,
+                        &format!("this looks like you are rotating elements of `{slice}` manually"),
+                        "try",
+                        format!("{}[{lo}..={hi}].{method}(1)", slice.maybe_par()),
+                        applicability,
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    if !places_are_disjoint(cx, places) {
+        return;
+    }
+
+    let suggs: Vec<_> = places
+        .iter()
+        .map(|place| Sugg::hir_with_applicability(cx, place, "..", &mut applicability))
+        .collect();
+    let lhs = suggs.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+    let rhs = suggs[1..]
+        .iter()
+        .chain(std::iter::once(&suggs[0]))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_ROTATION,
+        span,
+        "this looks like you are rotating variables manually",
+        "try",
+        format!("({lhs}) = ({rhs})"),
+        applicability,
+    );
+}
+
 /// Implementation of the `ALMOST_SWAPPED` lint.
 fn check_suspicious_swap(cx: &LateContext<'_>, block: &Block<'_>) {
     for [first, second] in block.stmts.array_windows() {
@@ -207,6 +419,45 @@ fn check_suspicious_swap(cx: &LateContext<'_>, block: &Block<'_>) {
     }
 }
 
+/// Implementation of the `ALMOST_SWAPPED` lint for a tuple-destructuring
+/// assignment such as `(a, b) = (a, b);`. Unlike `(a, b) = (b, a);`, which is
+/// already the idiomatic way to swap and is left untouched, this assigns
+/// every place to itself and does nothing.
+fn check_tuple_assign_swap(cx: &LateContext<'_>, block: &Block<'_>) {
+    for stmt in block.stmts {
+        if_chain! {
+            if let StmtKind::Semi(expr) = stmt.kind;
+            if let ExprKind::Assign(lhs, rhs, _) = expr.kind;
+            // Only the two-element case has an unambiguous fix (swap the two places); for
+            // three or more elements there's no single obviously-intended rewrite, so don't
+            // warn without being able to suggest one.
+            if let ExprKind::Tup([lhs1, lhs2]) = lhs.kind;
+            if let ExprKind::Tup([rhs1, rhs2]) = rhs.kind;
+            if is_same(cx, ExprOrIdent::Expr(lhs1), rhs1);
+            if is_same(cx, ExprOrIdent::Expr(lhs2), rhs2);
+            then {
+                span_lint_and_then(
+                    cx,
+                    ALMOST_SWAPPED,
+                    stmt.span,
+                    "this assigns every place to itself, which does nothing",
+                    |diag| {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let first = Sugg::hir_with_applicability(cx, lhs1, "..", &mut applicability);
+                        let second = Sugg::hir_with_applicability(cx, lhs2, "..", &mut applicability);
+                        diag.span_suggestion(
+                            stmt.span,
+                            "swap the values instead",
+                            format!("({first}, {second}) = ({second}, {first});"),
+                            applicability,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
 fn is_same(cx: &LateContext<'_>, lhs: ExprOrIdent<'_>, rhs: &Expr<'_>) -> bool {
     match lhs {
         ExprOrIdent::Expr(expr) => eq_expr_value(cx, expr, rhs),